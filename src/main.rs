@@ -1,15 +1,38 @@
 use bevy::{prelude::*, window::WindowTheme, ecs::query, render::extract_resource::ExtractResource};
 use bevy_egui::{
-    egui::{self, Hyperlink},
+    egui::{
+        self,
+        plot::{Line, Plot, PlotPoints},
+        Hyperlink,
+    },
     EguiContexts, EguiPlugin,
 };
 use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
+use bevy_polyline::{
+    prelude::{Polyline, PolylineBundle, PolylineMaterial},
+    PolylinePlugin,
+};
 use pdrust::settings::SettingsResource;
-use pdrust::{body::bundle::RigidBodyBundle, constraint::pulley::bundle::PulleyBundle};
+use pdrust::{
+    body::{
+        bundle::RigidBodyBundle, inertia::Inertia, mass::Mass,
+        velocity::{AngularVelocity, LinearVelocity},
+    },
+    constraint::pulley::{bundle::PulleyBundle, Pulley},
+};
+
+mod scene;
+use scene::{BodyShape, SceneDescription};
 
 use git_version::git_version;
 const GIT_VERSION: &str = git_version!();
 
+/// Maximum number of points kept in a trace polyline before the oldest are dropped.
+const MAX_TRACE_POINTS: usize = 2048;
+
+/// Maximum number of `(sim_time, total_energy)` samples kept for the energy plot.
+const MAX_ENERGY_SAMPLES: usize = 4096;
+
 #[derive(Resource, Debug, Component, PartialEq, Clone)]
 struct DemonstrationSettings {
     pub m1: f32,
@@ -18,10 +41,74 @@ struct DemonstrationSettings {
     pub l: f32,
     pub x_0: f32,
     pub enable_tracing: bool,
-    tracing_material: Option<Handle<StandardMaterial>>,
-    tracing_mesh: Option<Handle<Mesh>>,
+    tracing_material: Option<Handle<PolylineMaterial>>,
+}
+
+/// Opt-in debug visualization for `pdrust` constraints: tension-colored rope
+/// gizmos, anchor crosses and the per-substep constraint-violation vector.
+/// Lives here rather than on `pdrust::settings::SettingsResource` because
+/// these are purely presentational (gizmo on/off, color thresholds) and
+/// don't affect the solver `SettingsResource` configures.
+#[derive(Resource, Debug, Clone, PartialEq)]
+struct ConstraintDebugSettings {
+    pub enabled: bool,
+    pub show_violation: bool,
+    pub min_tension_color: Color,
+    pub max_tension_color: Color,
+    pub max_tension: f32,
+}
+
+impl Default for ConstraintDebugSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            show_violation: false,
+            min_tension_color: Color::GREEN,
+            max_tension_color: Color::RED,
+            max_tension: 500.0,
+        }
+    }
+}
+
+/// Camera offset relative to the followed target, used by the "Follow
+/// central mass" chase-camera mode: `rot` orbits around the target, `dist`
+/// places the camera back along the look direction, and `alt` raises it
+/// above the target.
+#[derive(Resource, Debug, Clone, PartialEq)]
+struct CamOffset {
+    pub follow_enabled: bool,
+    pub rot: f32,
+    pub dist: f32,
+    pub alt: f32,
 }
 
+impl Default for CamOffset {
+    fn default() -> Self {
+        Self {
+            follow_enabled: false,
+            rot: 0.0,
+            dist: 15.0,
+            alt: 5.0,
+        }
+    }
+}
+
+/// Bounded history of total mechanical energy, sampled every `FixedUpdate`,
+/// used to plot integrator drift and to compute percent drift relative to
+/// the energy at the last `RestartEvent`.
+#[derive(Resource, Debug, Default)]
+struct EnergyHistory {
+    samples: std::collections::VecDeque<(f32, f32)>,
+    sim_time: f32,
+    energy_at_last_restart: Option<f32>,
+}
+
+/// Holds the user's real `integration_substeps`/`constraints_substeps`
+/// while a "Step one substep" press has temporarily forced both to 1,
+/// so `simulation_settings_ui` can restore them once that tick has run.
+#[derive(Resource, Debug, Default)]
+struct PendingSubstepRestore(Option<(u32, u32)>);
+
 #[derive(Event)]
 struct RestartEvent;
 
@@ -31,8 +118,15 @@ struct CleanTraceEvent;
 #[derive(Component)]
 struct LeaveTrace;
 
+/// Marks a trail polyline entity, tagged with the body entity it traces.
 #[derive(Component)]
-struct MeshTrace;
+struct MeshTrace(Entity);
+
+/// Ring buffer of world-space points backing a `MeshTrace`, capped at
+/// `MAX_TRACE_POINTS` with O(1) eviction of the oldest point via
+/// `VecDeque::pop_front` instead of shifting a `Vec` on every tick.
+#[derive(Component, Default)]
+struct TracePoints(std::collections::VecDeque<Vec3>);
 
 impl Default for DemonstrationSettings {
     fn default() -> Self {
@@ -44,29 +138,27 @@ impl Default for DemonstrationSettings {
             x_0: -5.0,
             enable_tracing: false,
             tracing_material: None,
-            tracing_mesh: None,
         }
     }
 }
 
+/// Pushes each traced body's current position onto its `MeshTrace` polyline,
+/// creating the polyline on first use and keeping it bounded to
+/// `MAX_TRACE_POINTS` so tracing can stay on indefinitely without leaking
+/// entities or growing memory without bound.
 fn leave_trace_system(
-    transforms: Query<&Transform, With<LeaveTrace>>,
+    transforms: Query<(Entity, &Transform), With<LeaveTrace>>,
+    mut traces: Query<(&MeshTrace, &Handle<Polyline>, &mut TracePoints)>,
     mut settings: ResMut<DemonstrationSettings>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut polylines: ResMut<Assets<Polyline>>,
+    mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
 ) {
-    if settings.tracing_mesh.is_none() {
-        settings.tracing_mesh = Some(meshes.add(Mesh::from(shape::UVSphere {
-            radius: 0.1,
-            ..default()
-        })));
-    }
-
     if settings.tracing_material.is_none() {
-        settings.tracing_material = Some(materials.add(StandardMaterial {
-            base_color: Color::rgba(0.0, 1.0, 0.0, 0.5),
-            alpha_mode: AlphaMode::Add,
+        settings.tracing_material = Some(polyline_materials.add(PolylineMaterial {
+            width: 2.0,
+            color: Color::rgba(0.0, 1.0, 0.0, 0.5),
+            perspective: true,
             ..default()
         }));
     }
@@ -75,15 +167,36 @@ fn leave_trace_system(
         return;
     }
 
-    for t in &transforms {
+    for (entity, t) in &transforms {
+        let existing = traces.iter_mut().find(|(trace, ..)| trace.0 == entity);
+
+        if let Some((_, handle, mut points)) = existing {
+            points.0.push_back(t.translation);
+            let evicted = points.0.len() > MAX_TRACE_POINTS && points.0.pop_front().is_some();
+
+            let polyline = polylines.get_mut(handle).unwrap();
+            if evicted {
+                // Only an eviction needs a full rebuild; the common case
+                // (buffer not yet at capacity) is a plain O(1) push.
+                polyline.vertices.clear();
+                polyline.vertices.extend(points.0.iter().copied());
+            } else {
+                polyline.vertices.push(t.translation);
+            }
+            continue;
+        }
+
+        let polyline = polylines.add(Polyline {
+            vertices: vec![t.translation],
+        });
         commands.spawn((
-            PbrBundle {
-                mesh: settings.tracing_mesh.clone().unwrap(),
+            PolylineBundle {
+                polyline,
                 material: settings.tracing_material.clone().unwrap(),
-                transform: *t,
                 ..default()
             },
-            MeshTrace,
+            MeshTrace(entity),
+            TracePoints(std::collections::VecDeque::from([t.translation])),
         ));
     }
 }
@@ -104,15 +217,26 @@ fn main() {
         .add_plugins(EguiPlugin)
         .add_plugins(pdrust::PDRustPlugin)
         .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(PolylinePlugin)
         .insert_resource(DemonstrationSettings { ..default() })
+        .insert_resource(ConstraintDebugSettings::default())
+        .insert_resource(CamOffset::default())
+        .insert_resource(EnergyHistory::default())
+        .insert_resource(SceneDescription::default_demo(10.0, 10.0, 10.0, 10.0, -5.0))
+        .insert_resource(PendingSubstepRestore::default())
         .add_event::<RestartEvent>()
         .add_event::<CleanTraceEvent>()
         .add_systems(Startup, setup_camera_and_light)
         .add_systems(Update, demo_settings_ui)
         .add_systems(Update, simulation_settings_ui.after(demo_settings_ui))
         .add_systems(Update, restart_simulation)
+        .add_systems(Update, reset_energy_history.after(restart_simulation))
         .add_systems(FixedUpdate, leave_trace_system)
+        .add_systems(FixedUpdate, track_energy_system)
         .add_systems(Update, clean_trace)
+        .add_systems(Update, draw_constraint_gizmos)
+        .add_systems(Update, follow_camera_system.after(demo_settings_ui))
+        .add_systems(Update, energy_plot_ui)
         .run();
 }
 
@@ -150,6 +274,126 @@ fn setup_camera_and_light(
     restart_event.send(RestartEvent);
 }
 
+/// When `CamOffset::follow_enabled` is set, locks the camera onto a
+/// `LeaveTrace`-tagged body each frame, recomputing its transform from
+/// `rot`/`dist`/`alt` instead of leaving it to `PanOrbitCamera`. Scenes are
+/// data-driven (see `scene.rs`), so more than one body may be traced; this
+/// follows whichever comes first and warns if none exist. The free orbit
+/// camera remains available whenever the toggle is off.
+fn follow_camera_system(
+    cam_offset: Res<CamOffset>,
+    target: Query<&Transform, (With<LeaveTrace>, Without<Camera3d>)>,
+    mut camera: Query<(&mut Transform, &mut PanOrbitCamera), With<Camera3d>>,
+    mut already_warned: Local<bool>,
+) {
+    let Ok((mut camera_transform, mut pan_orbit)) = camera.get_single_mut() else {
+        return;
+    };
+
+    if !cam_offset.follow_enabled {
+        pan_orbit.enabled = true;
+        *already_warned = false;
+        return;
+    }
+
+    let Some(target_transform) = target.iter().next() else {
+        if !*already_warned {
+            warn!("Follow central mass is enabled, but no LeaveTrace body exists to follow");
+            *already_warned = true;
+        }
+        return;
+    };
+    *already_warned = false;
+
+    pan_orbit.enabled = false;
+
+    let target_pos = target_transform.translation;
+    let forward = Vec3::new(cam_offset.rot.cos(), 0.0, cam_offset.rot.sin());
+    camera_transform.translation = target_pos + forward * cam_offset.dist + Vec3::Y * cam_offset.alt;
+    camera_transform.look_at(target_pos, Vec3::Y);
+}
+
+/// Sums kinetic energy (`0.5*m*v·v`, plus `0.5*ω·Iω` for bodies exposing
+/// angular velocity and inertia) and potential energy (`m*g*h`) over every
+/// rigid body, and records `(sim_time, total_energy)` so drift can be
+/// plotted instead of eyeballed.
+fn track_energy_system(
+    time: Res<Time>,
+    settings: Res<SettingsResource>,
+    mut history: ResMut<EnergyHistory>,
+    bodies: Query<(
+        &Mass,
+        &LinearVelocity,
+        &Transform,
+        Option<&AngularVelocity>,
+        Option<&Inertia>,
+    )>,
+) {
+    history.sim_time += time.delta_seconds();
+    let gravity = settings.gravity.length();
+
+    let total_energy: f32 = bodies
+        .iter()
+        .map(|(mass, velocity, transform, angular_velocity, inertia)| {
+            let kinetic = 0.5 * mass.0 * velocity.0.length_squared();
+            let rotational = match (angular_velocity, inertia) {
+                (Some(omega), Some(i)) => 0.5 * i.0 * omega.0.length_squared(),
+                _ => 0.0,
+            };
+            let potential = mass.0 * gravity * transform.translation.y;
+            kinetic + rotational + potential
+        })
+        .sum();
+
+    if history.energy_at_last_restart.is_none() {
+        history.energy_at_last_restart = Some(total_energy);
+    }
+
+    history.samples.push_back((history.sim_time, total_energy));
+    if history.samples.len() > MAX_ENERGY_SAMPLES {
+        history.samples.pop_front();
+    }
+}
+
+/// Clears the energy history whenever the scene restarts, so drift is
+/// always reported relative to the current run.
+fn reset_energy_history(
+    mut ev_restart: EventReader<RestartEvent>,
+    mut history: ResMut<EnergyHistory>,
+) {
+    for _ in ev_restart.read() {
+        history.samples.clear();
+        history.sim_time = 0.0;
+        history.energy_at_last_restart = None;
+    }
+}
+
+fn energy_plot_ui(mut contexts: EguiContexts, history: Res<EnergyHistory>) {
+    egui::Window::new("Energy Conservation").show(contexts.ctx_mut(), |ui| {
+        let points: PlotPoints = history
+            .samples
+            .iter()
+            .map(|&(t, e)| [t as f64, e as f64])
+            .collect();
+        Plot::new("energy_plot")
+            .view_aspect(2.0)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new(points).name("Total mechanical energy"));
+            });
+
+        if let (Some(initial), Some(&(_, latest))) =
+            (history.energy_at_last_restart, history.samples.back())
+        {
+            let drift = if initial.abs() > f32::EPSILON {
+                (latest - initial) / initial * 100.0
+            } else {
+                0.0
+            };
+            ui.label(format!("Drift since last restart: {:.3}%", drift));
+        }
+    });
+}
+
 fn clean_trace(
     mut clean_trace_event: EventReader<CleanTraceEvent>,
     mut commands: Commands,
@@ -163,6 +407,8 @@ fn clean_trace(
 fn demo_settings_ui(
     mut contexts: EguiContexts,
     mut settings: ResMut<DemonstrationSettings>,
+    mut cam_offset: ResMut<CamOffset>,
+    mut scene: ResMut<SceneDescription>,
     mut restart_event: EventWriter<RestartEvent>,
     mut clean_trace_event: EventWriter<CleanTraceEvent>,
 ) {
@@ -175,16 +421,48 @@ fn demo_settings_ui(
         ui.add(egui::Slider::new(&mut settings.x_0, -l..=l).text("x_0"));
         if ui.add(egui::Checkbox::new(
             &mut settings.enable_tracing,
-            "Enable tracing (may cause severe perfomance loss!)",
+            "Enable tracing",
         )).clicked() {
             if !settings.enable_tracing {
                 clean_trace_event.send(CleanTraceEvent);
             }
         };
         if ui.button("Start").clicked() {
+            *scene = SceneDescription::default_demo(
+                settings.m1,
+                settings.m2,
+                settings.mc,
+                settings.l,
+                settings.x_0,
+            );
             restart_event.send(RestartEvent);
         }
 
+        if ui.button("Load scene...").clicked() {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("scene", &["ron", "json"])
+                .pick_file()
+            {
+                match SceneDescription::load_from_file(&path) {
+                    Ok(loaded) => {
+                        *scene = loaded;
+                        restart_event.send(RestartEvent);
+                    }
+                    Err(err) => {
+                        warn!("failed to load scene from {path:?}: {err}");
+                    }
+                }
+            }
+        }
+
+        ui.separator();
+        ui.checkbox(&mut cam_offset.follow_enabled, "Follow central mass");
+        if cam_offset.follow_enabled {
+            ui.add(egui::Slider::new(&mut cam_offset.rot, 0.0..=std::f32::consts::TAU).text("rot"));
+            ui.add(egui::Slider::new(&mut cam_offset.dist, 2.0..=40.0).text("dist"));
+            ui.add(egui::Slider::new(&mut cam_offset.alt, -20.0..=20.0).text("alt"));
+        }
+
         ui.horizontal(|ui| {
             ui.label(format!("Git version:"));
             ui.add(Hyperlink::from_label_and_url(
@@ -198,7 +476,38 @@ fn demo_settings_ui(
     });
 }
 
-fn simulation_settings_ui(mut contexts: EguiContexts, mut settings: ResMut<SettingsResource>) {
+/// The only temporal control besides `slow_motion_koef`: Pause/Resume
+/// stop and restart the global `Time<Virtual>` clock. `leave_trace_system`
+/// and the `PDRustPlugin` solver both run in `FixedUpdate`, which is driven
+/// off `Time<Virtual>`, so pausing it should freeze them in lockstep
+/// without a bespoke flag threaded through each system — this assumption
+/// can't be confirmed against `pdrust`'s own source from this tree, so
+/// verify it holds (the pulley should visibly stop moving on Pause) before
+/// relying on it.
+///
+/// Step advances virtual time by exactly one fixed timestep while paused.
+/// Since `integration_substeps`/`constraints_substeps` bind live to
+/// `SettingsResource` (the sliders above already prove `pdrust` re-reads
+/// them every tick), a true single-substep step is obtained by forcing
+/// both to 1 for that one tick and restoring the user's values immediately
+/// afterwards, via `PendingSubstepRestore`.
+fn simulation_settings_ui(
+    mut contexts: EguiContexts,
+    mut settings: ResMut<SettingsResource>,
+    mut debug_settings: ResMut<ConstraintDebugSettings>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    fixed_time: Res<Time<Fixed>>,
+    mut pending_substep_restore: ResMut<PendingSubstepRestore>,
+) {
+    // Restore the substep counts forced by a previous "Step one substep"
+    // press: FixedUpdate for this frame has already run by the time Update
+    // (and this system) executes, so it's safe to put the user's values
+    // back now.
+    if let Some((integration, constraints)) = pending_substep_restore.0.take() {
+        settings.integration_substeps = integration;
+        settings.constraints_substeps = constraints;
+    }
+
     egui::Window::new("Simulation Settings").show(contexts.ctx_mut(), |ui| {
         ui.add(
             egui::Slider::new(&mut settings.integration_substeps, 1..=32)
@@ -216,115 +525,202 @@ fn simulation_settings_ui(mut contexts: EguiContexts, mut settings: ResMut<Setti
             egui::Slider::new(&mut settings.slow_motion_koef, 1.0..=16.0)
                 .text("Slow Motion coefficient"),
         );
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            if virtual_time.is_paused() {
+                if ui.button("Resume").clicked() {
+                    virtual_time.unpause();
+                }
+                if ui.button("Step one substep").clicked() {
+                    pending_substep_restore.0 =
+                        Some((settings.integration_substeps, settings.constraints_substeps));
+                    settings.integration_substeps = 1;
+                    settings.constraints_substeps = 1;
+                    virtual_time.advance_by(fixed_time.timestep());
+                }
+            } else if ui.button("Pause").clicked() {
+                virtual_time.pause();
+            }
+        });
+
+        ui.separator();
+        ui.checkbox(&mut debug_settings.enabled, "Show constraint gizmos");
+        if debug_settings.enabled {
+            ui.checkbox(
+                &mut debug_settings.show_violation,
+                "Show constraint-violation vector",
+            );
+            ui.add(
+                egui::Slider::new(&mut debug_settings.max_tension, 1.0..=2000.0)
+                    .text("Max rope tension (color scale)"),
+            );
+
+            ui.horizontal(|ui| {
+                ui.label("0 tension color");
+                let mut color = debug_settings.min_tension_color.as_rgba_f32();
+                if ui.color_edit_button_rgba_unmultiplied(&mut color).changed() {
+                    debug_settings.min_tension_color =
+                        Color::rgba(color[0], color[1], color[2], color[3]);
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Max tension color");
+                let mut color = debug_settings.max_tension_color.as_rgba_f32();
+                if ui.color_edit_button_rgba_unmultiplied(&mut color).changed() {
+                    debug_settings.max_tension_color =
+                        Color::rgba(color[0], color[1], color[2], color[3]);
+                }
+            });
+        }
     });
 }
 
+/// Draws gizmo line segments from each pulley-attached body to its anchor,
+/// colored by current rope tension (lerped from `min_tension_color` at 0 to
+/// `max_tension_color` at `max_tension`), a cross at the anchor point, and
+/// optionally the constraint-violation vector (how far the length constraint
+/// is from satisfied this substep), to make the Baumgarte-stabilized solve
+/// directly inspectable while tuning `baumgarte_constant` and substep counts.
+fn draw_constraint_gizmos(
+    debug_settings: Res<ConstraintDebugSettings>,
+    pulleys: Query<&Pulley>,
+    transforms: Query<&Transform>,
+    mut gizmos: Gizmos,
+) {
+    if !debug_settings.enabled {
+        return;
+    }
+
+    const CROSS_SIZE: f32 = 0.3;
+
+    for pulley in &pulleys {
+        let t = (pulley.tension / debug_settings.max_tension).clamp(0.0, 1.0);
+        let color = debug_settings
+            .min_tension_color
+            .lerp(debug_settings.max_tension_color, t);
+
+        gizmos.line(
+            pulley.anchor - Vec3::X * CROSS_SIZE,
+            pulley.anchor + Vec3::X * CROSS_SIZE,
+            Color::YELLOW,
+        );
+        gizmos.line(
+            pulley.anchor - Vec3::Y * CROSS_SIZE,
+            pulley.anchor + Vec3::Y * CROSS_SIZE,
+            Color::YELLOW,
+        );
+        gizmos.line(
+            pulley.anchor - Vec3::Z * CROSS_SIZE,
+            pulley.anchor + Vec3::Z * CROSS_SIZE,
+            Color::YELLOW,
+        );
+
+        let mut total_length = 0.0;
+        for body in [pulley.body_a, pulley.body_b] {
+            let Ok(body_transform) = transforms.get(body) else {
+                continue;
+            };
+            gizmos.line(body_transform.translation, pulley.anchor, color);
+            total_length += (body_transform.translation - pulley.anchor).length();
+        }
+
+        if debug_settings.show_violation {
+            let violation = total_length - pulley.rope_length;
+            if let Ok(a_transform) = transforms.get(pulley.body_a) {
+                let direction = (pulley.anchor - a_transform.translation).normalize_or_zero();
+                gizmos.line(
+                    a_transform.translation,
+                    a_transform.translation + direction * violation,
+                    Color::FUCHSIA,
+                );
+            }
+        }
+    }
+}
+
+/// Spawns the `RigidBodyBundle`s and `PulleyBundle`s described by `scene`,
+/// iterating its bodies and constraints by index instead of hard-coding the
+/// two-pulley / three-mass layout, so new setups can be authored without
+/// recompiling.
 fn restart_simulation(
     mut ev_restart: EventReader<RestartEvent>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     meshes_query: Query<Entity, With<Handle<Mesh>>>,
-    settings: Res<DemonstrationSettings>,
+    traces_query: Query<Entity, With<MeshTrace>>,
+    scene: Res<SceneDescription>,
 ) {
+    const PULLEY_RADIUS: f32 = 0.25;
+
     for _ev in ev_restart.read() {
         for e in meshes_query.iter() {
             commands.entity(e).despawn();
         }
+        for e in traces_query.iter() {
+            commands.entity(e).despawn();
+        }
 
-        let half_l = settings.l / 2.0;
-        let m1 = settings.m1;
-        let m2 = settings.m2;
-        let m_central = settings.mc;
-
-        let equilibrium_pos = Vec3::new(0.0, -half_l / f32::sqrt(3.0), 0.0);
-        let equilibrium_offset: f32 = settings.x_0;
-        let b_central_pos = equilibrium_pos + Vec3::new(0.0, equilibrium_offset, 0.0);
-
-        let pulley1_pos = Vec3::new(-half_l, 0.0, 0.0);
-        let pulley2_pos = Vec3::new(half_l, 0.0, 0.0);
-
-        let constraint_distance = 3.0 * half_l;
-        let vertical_offset = constraint_distance - (b_central_pos - pulley1_pos).length();
-        let b1_pos = pulley1_pos + Vec3::new(0.0, -vertical_offset, 0.0);
-        let b2_pos = pulley2_pos + Vec3::new(0.0, -vertical_offset, 0.0);
-
-        let b1 = RigidBodyBundle::spawn_new_box(
-            &mut commands,
-            &mut meshes,
-            materials.add(Color::RED.into()),
-            m1,
-            1.0,
-            1.0,
-            1.0,
-            Transform::from_translation(b1_pos),
-            Vec3::ZERO,
-            Vec3::ZERO,
-        );
-
-        let b2 = RigidBodyBundle::spawn_new_box(
-            &mut commands,
-            &mut meshes,
-            materials.add(Color::RED.into()),
-            m2,
-            1.0,
-            1.0,
-            1.0,
-            Transform::from_translation(b2_pos),
-            Vec3::ZERO,
-            Vec3::ZERO,
-        );
-
-        let central_body = RigidBodyBundle::spawn_new_sphere(
-            &mut commands,
-            &mut meshes,
-            materials.add(Color::GREEN.into()),
-            m_central,
-            0.5,
-            Transform::from_translation(b_central_pos),
-            Vec3::ZERO,
-            Vec3::ZERO,
-        );
-        commands.entity(central_body).insert(LeaveTrace);
-
-        PulleyBundle::spawn_new(
-            &mut commands,
-            &mut meshes,
-            materials.add(Color::MIDNIGHT_BLUE.into()),
-            materials.add(Color::MIDNIGHT_BLUE.into()),
-            materials.add(Color::BEIGE.into()),
-            b1,
-            central_body,
-            Vec3::ZERO,
-            Vec3::ZERO,
-            constraint_distance,
-            pulley1_pos,
-        );
-
-        PulleyBundle::spawn_new(
-            &mut commands,
-            &mut meshes,
-            materials.add(Color::MIDNIGHT_BLUE.into()),
-            materials.add(Color::MIDNIGHT_BLUE.into()),
-            materials.add(Color::BEIGE.into()),
-            b2,
-            central_body,
-            Vec3::ZERO,
-            Vec3::ZERO,
-            constraint_distance,
-            pulley2_pos,
-        );
+        let bodies: Vec<Entity> = scene
+            .bodies
+            .iter()
+            .map(|body| {
+                let entity = match body.shape {
+                    BodyShape::Box { size_x, size_y, size_z } => RigidBodyBundle::spawn_new_box(
+                        &mut commands,
+                        &mut meshes,
+                        materials.add(Color::RED.into()),
+                        body.mass,
+                        size_x,
+                        size_y,
+                        size_z,
+                        Transform::from_translation(body.position),
+                        body.velocity,
+                        body.angular_velocity,
+                    ),
+                    BodyShape::Sphere { radius } => RigidBodyBundle::spawn_new_sphere(
+                        &mut commands,
+                        &mut meshes,
+                        materials.add(Color::GREEN.into()),
+                        body.mass,
+                        radius,
+                        Transform::from_translation(body.position),
+                        body.velocity,
+                        body.angular_velocity,
+                    ),
+                };
+                if body.trace {
+                    commands.entity(entity).insert(LeaveTrace);
+                }
+                entity
+            })
+            .collect();
 
-        let pulley_radius = 0.25;
+        for pulley in &scene.pulleys {
+            PulleyBundle::spawn_new(
+                &mut commands,
+                &mut meshes,
+                materials.add(Color::MIDNIGHT_BLUE.into()),
+                materials.add(Color::MIDNIGHT_BLUE.into()),
+                materials.add(Color::BEIGE.into()),
+                bodies[pulley.body_a],
+                bodies[pulley.body_b],
+                Vec3::ZERO,
+                Vec3::ZERO,
+                pulley.rope_length,
+                pulley.anchor,
+            );
 
-        commands.spawn(PbrBundle {
-            mesh: meshes.add(Mesh::from(shape::UVSphere {
-                radius: pulley_radius,
+            commands.spawn(PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::UVSphere {
+                    radius: PULLEY_RADIUS,
+                    ..default()
+                })),
+                material: materials.add(Color::CYAN.into()),
+                transform: Transform::from_translation(pulley.anchor),
                 ..default()
-            })),
-            material: materials.add(Color::CYAN.into()),
-            transform: Transform::from_translation(equilibrium_pos),
-            ..default()
-        });
+            });
+        }
     }
 }
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Shape of a scene body, mirroring the constructors exposed by
+/// `pdrust::body::bundle::RigidBodyBundle`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BodyShape {
+    Box { size_x: f32, size_y: f32, size_z: f32 },
+    Sphere { radius: f32 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BodyDescription {
+    pub shape: BodyShape,
+    pub mass: f32,
+    pub position: Vec3,
+    #[serde(default)]
+    pub velocity: Vec3,
+    #[serde(default)]
+    pub angular_velocity: Vec3,
+    #[serde(default)]
+    pub trace: bool,
+}
+
+/// A `PulleyBundle` connecting two bodies (by index into `SceneDescription::bodies`)
+/// over a fixed-length rope anchored at `anchor`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PulleyDescription {
+    pub body_a: usize,
+    pub body_b: usize,
+    pub anchor: Vec3,
+    pub rope_length: f32,
+}
+
+/// A full constraint-sandbox scene: bodies to spawn and the constraints
+/// connecting them, loaded from a RON or JSON file instead of being
+/// hard-coded in `restart_simulation`.
+#[derive(Debug, Clone, Serialize, Deserialize, Resource)]
+pub struct SceneDescription {
+    pub bodies: Vec<BodyDescription>,
+    pub pulleys: Vec<PulleyDescription>,
+}
+
+impl SceneDescription {
+    /// Rebuilds the two-pulley / three-mass demo layout from the
+    /// `DemonstrationSettings` sliders, matching the scene that used to be
+    /// hard-coded directly in `restart_simulation`.
+    pub fn default_demo(m1: f32, m2: f32, mc: f32, l: f32, x_0: f32) -> Self {
+        let half_l = l / 2.0;
+
+        let equilibrium_pos = Vec3::new(0.0, -half_l / f32::sqrt(3.0), 0.0);
+        let b_central_pos = equilibrium_pos + Vec3::new(0.0, x_0, 0.0);
+
+        let pulley1_pos = Vec3::new(-half_l, 0.0, 0.0);
+        let pulley2_pos = Vec3::new(half_l, 0.0, 0.0);
+
+        let constraint_distance = 3.0 * half_l;
+        let vertical_offset = constraint_distance - (b_central_pos - pulley1_pos).length();
+        let b1_pos = pulley1_pos + Vec3::new(0.0, -vertical_offset, 0.0);
+        let b2_pos = pulley2_pos + Vec3::new(0.0, -vertical_offset, 0.0);
+
+        Self {
+            bodies: vec![
+                BodyDescription {
+                    shape: BodyShape::Box { size_x: 1.0, size_y: 1.0, size_z: 1.0 },
+                    mass: m1,
+                    position: b1_pos,
+                    velocity: Vec3::ZERO,
+                    angular_velocity: Vec3::ZERO,
+                    trace: false,
+                },
+                BodyDescription {
+                    shape: BodyShape::Box { size_x: 1.0, size_y: 1.0, size_z: 1.0 },
+                    mass: m2,
+                    position: b2_pos,
+                    velocity: Vec3::ZERO,
+                    angular_velocity: Vec3::ZERO,
+                    trace: false,
+                },
+                BodyDescription {
+                    shape: BodyShape::Sphere { radius: 0.5 },
+                    mass: mc,
+                    position: b_central_pos,
+                    velocity: Vec3::ZERO,
+                    angular_velocity: Vec3::ZERO,
+                    trace: true,
+                },
+            ],
+            pulleys: vec![
+                PulleyDescription {
+                    body_a: 0,
+                    body_b: 2,
+                    anchor: pulley1_pos,
+                    rope_length: constraint_distance,
+                },
+                PulleyDescription {
+                    body_a: 1,
+                    body_b: 2,
+                    anchor: pulley2_pos,
+                    rope_length: constraint_distance,
+                },
+            ],
+        }
+    }
+
+    /// Loads a scene from a RON or JSON file, picking the format by extension.
+    pub fn load_from_file(path: &std::path::Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let scene: Self = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&contents).map_err(|e| e.to_string())?,
+            _ => ron::from_str(&contents).map_err(|e| e.to_string())?,
+        };
+        scene.validate()?;
+        Ok(scene)
+    }
+
+    /// Checks that every pulley references bodies that actually exist, so a
+    /// hand-authored scene file fails to load with a message instead of
+    /// panicking `restart_simulation` with an out-of-bounds index.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, pulley) in self.pulleys.iter().enumerate() {
+            for index in [pulley.body_a, pulley.body_b] {
+                if index >= self.bodies.len() {
+                    return Err(format!(
+                        "pulley {i} references body index {index}, but the scene only has {} bodies",
+                        self.bodies.len()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}